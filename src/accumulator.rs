@@ -0,0 +1,186 @@
+//! `Accumulator<T>`, for running many fallible sub-checks inside a `validate` implementation and
+//! collecting all of their errors instead of bailing out at the first one.
+//!
+//! This is the same idea as the `validators` functions combined with `Validation::and_check`, but
+//! suited to checks that are themselves `Result<(), Errors<T>>` (as produced by a sub-field's own
+//! `validate`) rather than single `Error<T>` values, and to building up a whole type's errors
+//! field by field rather than chaining within one field.
+
+use std::any::Any;
+use std::collections::hash_map::Entry;
+use std::fmt::Debug;
+
+use {Error, Errors};
+
+/// Collects validation errors from multiple checks, to be returned together as one `Errors<T>`.
+pub struct Accumulator<T> where T: Debug + Any {
+    errors: Errors<T>,
+}
+
+impl<T> Accumulator<T> where T: Debug + Any {
+    /// Constructs an empty `Accumulator`.
+    pub fn new() -> Self {
+        Accumulator {
+            errors: Errors::new(),
+        }
+    }
+
+    /// Adds a validation error that is not specific to any field.
+    pub fn add_error(&mut self, error: Error<T>) {
+        self.errors.add_error(error);
+    }
+
+    /// Adds a validation error for the given field.
+    pub fn add_field_error<S>(&mut self, field: S, error: Error<T>) where S: Into<String> {
+        self.errors.add_field_error(field, error);
+    }
+
+    /// Merges the `Errors` from a sub-field's own `validate()` call under `field`.
+    ///
+    /// If `field` already has errors, from an earlier call to `push_result`, `add_field_error`,
+    /// or `merge`, the two error trees are merged rather than one overwriting the other.
+    pub fn push_result<S>(&mut self, field: S, result: Result<(), Errors<T>>) where S: Into<String> {
+        if let Err(nested) = result {
+            let mut wrapper = Errors::new();
+
+            wrapper.set_field_errors(field, nested);
+
+            self.merge(wrapper);
+        }
+    }
+
+    /// Unions `other` into the errors accumulated so far: `base` vectors are concatenated, and
+    /// `fields` maps are merged key by key, recursing into `Errors` that share a field name.
+    pub fn merge(&mut self, other: Errors<T>) {
+        merge_errors(&mut self.errors, other);
+    }
+
+    /// Finishes accumulating, returning `Ok(())` if no errors were collected, or `Err` with all
+    /// of them otherwise.
+    pub fn finish(self) -> Result<(), Errors<T>> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(self.errors)
+        }
+    }
+}
+
+impl<T> Default for Accumulator<T> where T: Debug + Any {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn merge_errors<T>(target: &mut Errors<T>, other: Errors<T>) where T: Debug + Any {
+    if let Some(other_base) = other.base {
+        match target.base {
+            Some(ref mut base) => base.extend(other_base),
+            None => target.base = Some(other_base),
+        }
+    }
+
+    if let Some(other_fields) = other.fields {
+        match target.fields {
+            Some(ref mut fields) => {
+                for (field, other_field_errors) in other_fields {
+                    match fields.entry(field) {
+                        Entry::Occupied(mut entry) => {
+                            merge_errors(&mut *entry.get_mut(), *other_field_errors);
+                        }
+                        Entry::Vacant(entry) => {
+                            entry.insert(other_field_errors);
+                        }
+                    }
+                }
+            }
+            None => target.fields = Some(other_fields),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Accumulator;
+    use {Error, Errors};
+
+    #[test]
+    fn finish_succeeds_with_no_errors() {
+        let accumulator: Accumulator<()> = Accumulator::new();
+
+        assert!(accumulator.finish().is_ok());
+    }
+
+    #[test]
+    fn add_error_and_add_field_error_both_contribute() {
+        let mut accumulator: Accumulator<()> = Accumulator::new();
+
+        accumulator.add_error(Error::new("at least one phone number is required"));
+        accumulator.add_field_error("name", Error::new("can't be blank"));
+
+        let errors = accumulator.finish().err().unwrap();
+
+        assert_eq!(errors.base().unwrap()[0].message(), "at least one phone number is required");
+        assert_eq!(errors.field("name").unwrap().base().unwrap()[0].message(), "can't be blank");
+    }
+
+    #[test]
+    fn push_result_merges_a_sub_fields_errors() {
+        let mut accumulator: Accumulator<()> = Accumulator::new();
+
+        let mut sub_errors = Errors::new();
+        sub_errors.add_error(Error::new("must contain an @ symbol"));
+
+        accumulator.push_result("email", Err(sub_errors));
+
+        let errors = accumulator.finish().err().unwrap();
+
+        assert_eq!(errors.field("email").unwrap().base().unwrap()[0].message(), "must contain an @ symbol");
+    }
+
+    #[test]
+    fn push_result_merges_with_existing_field_errors_instead_of_overwriting() {
+        let mut accumulator: Accumulator<()> = Accumulator::new();
+
+        accumulator.add_field_error("email", Error::new("can't be blank"));
+
+        let mut sub_errors = Errors::new();
+        sub_errors.add_error(Error::new("must contain an @ symbol"));
+
+        accumulator.push_result("email", Err(sub_errors));
+
+        let errors = accumulator.finish().err().unwrap();
+        let messages: Vec<&str> = errors.field("email").unwrap().base().unwrap().iter()
+            .map(Error::message)
+            .collect();
+
+        assert_eq!(messages, vec!["can't be blank", "must contain an @ symbol"]);
+    }
+
+    #[test]
+    fn merge_unions_base_and_recurses_into_shared_fields() {
+        let mut accumulator: Accumulator<()> = Accumulator::new();
+
+        accumulator.add_error(Error::new("a"));
+        accumulator.add_field_error("name", Error::new("can't be blank"));
+
+        let mut other = Errors::new();
+
+        other.add_error(Error::new("b"));
+        other.add_field_error("name", Error::new("is too long"));
+        other.add_field_error("email", Error::new("is invalid"));
+
+        accumulator.merge(other);
+
+        let errors = accumulator.finish().err().unwrap();
+
+        let base_messages: Vec<&str> = errors.base().unwrap().iter().map(Error::message).collect();
+        let name_messages: Vec<&str> = errors.field("name").unwrap().base().unwrap().iter()
+            .map(Error::message)
+            .collect();
+
+        assert_eq!(base_messages, vec!["a", "b"]);
+        assert_eq!(name_messages, vec!["can't be blank", "is too long"]);
+        assert_eq!(errors.field("email").unwrap().base().unwrap()[0].message(), "is invalid");
+    }
+}
@@ -0,0 +1,169 @@
+//! Combinators for composing individual checks into one `Errors<T>`.
+//!
+//! A single field often has more than one constraint, and the constraints are typically
+//! expressed as a series of `Result<(), Error<T>>` values, one per check (for example, the
+//! functions in the `validators` module). `Result`'s own `map_err` already composes well for
+//! rewriting a message:
+//!
+//! ```ignore
+//! validators::length(&name, Some(1), None, None, None).map_err(msg!("name too short"))
+//! ```
+//!
+//! What's missing from `std` is a way to invert a predicate-based check and a way to run several
+//! checks while keeping every failure instead of stopping at the first one. `OrElse::or_check`
+//! fills the first gap: it recovers a failed check when an inverted predicate says the value is
+//! actually fine. `Validation::and_check` fills the second: it sequences another check after this
+//! one and merges both into a single `Errors<T>`, so a field reports all of its violations at
+//! once.
+
+use std::any::Any;
+use std::fmt::Debug;
+
+use {Error, Errors};
+
+/// Builds a replacement `Error` from a format string, for use with `Result::map_err`.
+///
+/// ```ignore
+/// length(&name, Some(1), None, None, None).map_err(msg!("\"{}\" is too short", name))
+/// ```
+#[macro_export]
+macro_rules! msg {
+    ($($arg:tt)*) => {
+        |_| $crate::Error::new(format!($($arg)*))
+    };
+}
+
+/// Inverts a predicate-based check: recovers a failed check when `predicate` says the value is
+/// actually valid after all.
+pub trait OrElse<T> where T: Debug + Any {
+    /// Recovers from this check's failure when `predicate` inverts it back to valid, i.e. when
+    /// the check failed but `predicate` returns `true`.
+    fn or_check<F>(self, predicate: F) -> Result<(), Error<T>> where F: FnOnce() -> bool;
+}
+
+impl<T> OrElse<T> for Result<(), Error<T>> where T: Debug + Any {
+    fn or_check<F>(self, predicate: F) -> Result<(), Error<T>> where F: FnOnce() -> bool {
+        match self {
+            Ok(()) => Ok(()),
+            Err(error) => if predicate() { Ok(()) } else { Err(error) },
+        }
+    }
+}
+
+/// Sequences validation checks so that every failure is collected into one `Errors<T>`, rather
+/// than stopping at the first one.
+pub trait Validation<T> where T: Debug + Any {
+    /// Runs `other` regardless of whether this check already failed, and merges both outcomes
+    /// into a single `Errors<T>`.
+    fn and_check<F>(self, other: F) -> Result<(), Errors<T>> where F: FnOnce() -> Result<(), Error<T>>;
+}
+
+impl<T> Validation<T> for Result<(), Error<T>> where T: Debug + Any {
+    fn and_check<F>(self, other: F) -> Result<(), Errors<T>> where F: FnOnce() -> Result<(), Error<T>> {
+        let mut errors = Errors::new();
+
+        if let Err(error) = self {
+            errors.add_error(error);
+        }
+
+        if let Err(error) = other() {
+            errors.add_error(error);
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+impl<T> Validation<T> for Result<(), Errors<T>> where T: Debug + Any {
+    fn and_check<F>(self, other: F) -> Result<(), Errors<T>> where F: FnOnce() -> Result<(), Error<T>> {
+        let mut errors = match self {
+            Ok(()) => Errors::new(),
+            Err(errors) => errors,
+        };
+
+        if let Err(error) = other() {
+            errors.add_error(error);
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OrElse, Validation};
+    use {Error, Errors};
+
+    fn check(ok: bool, message: &'static str) -> Result<(), Error<()>> {
+        if ok {
+            Ok(())
+        } else {
+            Err(Error::new(message))
+        }
+    }
+
+    #[test]
+    fn and_succeeds_when_both_checks_succeed() {
+        let result: Result<(), Errors<()>> = check(true, "a").and_check(|| check(true, "b"));
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn and_collects_both_failures() {
+        let result: Result<(), Errors<()>> = check(false, "a").and_check(|| check(false, "b"));
+
+        let errors = result.err().unwrap();
+        let messages: Vec<&str> = errors.base().unwrap().iter().map(Error::message).collect();
+
+        assert_eq!(messages, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn and_chains_across_more_than_two_checks() {
+        let result: Result<(), Errors<()>> = check(false, "a")
+            .and_check(|| check(true, "b"))
+            .and_check(|| check(false, "c"));
+
+        let errors = result.err().unwrap();
+        let messages: Vec<&str> = errors.base().unwrap().iter().map(Error::message).collect();
+
+        assert_eq!(messages, vec!["a", "c"]);
+    }
+
+    #[test]
+    fn map_err_rewrites_the_message() {
+        let result: Result<(), Error<()>> = check(false, "original").map_err(msg!("replacement"));
+
+        assert_eq!(result.err().unwrap().message(), "replacement");
+    }
+
+    #[test]
+    fn or_else_recovers_when_the_predicate_inverts_the_failure() {
+        let result = check(false, "original").or_check(|| true);
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn or_else_keeps_the_failure_when_the_predicate_does_not_invert_it() {
+        let result = check(false, "original").or_check(|| false);
+
+        assert_eq!(result.err().unwrap().message(), "original");
+    }
+
+    #[test]
+    fn or_else_does_not_run_the_predicate_when_the_check_already_succeeded() {
+        let result = check(true, "original").or_check(|| panic!("predicate should not run"));
+
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,98 @@
+//! A type-erased mode for `Error`/`Errors`, for when different fields need different detail
+//! types within the same error tree.
+//!
+//! `Errors<T>` fixes a single details type `T` for the whole tree, which is awkward when, say,
+//! one field wants an `InvalidCharacters` detail and another wants a `RangeViolation`. `DynError`
+//! and `DynErrors` use a type-erased `Box<dyn AnyDebug>` as the details type instead, and
+//! `DynError::details_as` downcasts back to a concrete type when a caller knows what to expect.
+
+use std::any::Any;
+use std::fmt::Debug;
+
+use Error;
+
+/// A trait object safe combination of `Any` and `Debug`, used as the details type for `DynError`.
+///
+/// This exists because a trait object can't be built directly from `Any + Debug` (a trait object
+/// may only name one non-auto trait); `AnyDebug` is a single trait that both are blanket-
+/// implemented for.
+///
+/// `dyn AnyDebug` already implements `Debug` for free because `Debug` is one of its supertraits
+/// (the same reason `dyn std::error::Error` has always implemented `Debug`/`Display`), so there's
+/// no explicit `impl Debug for dyn AnyDebug` here — adding one would conflict with that automatic
+/// impl.
+pub trait AnyDebug: Any + Debug {
+    /// Upcasts to `&dyn Any`, for downcasting back to a concrete type.
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<T> AnyDebug for T where T: Any + Debug {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// An `Error` whose details are type-erased, so different `DynError`s in the same `DynErrors`
+/// tree can carry different concrete detail types.
+pub type DynError = Error<Box<dyn AnyDebug>>;
+
+/// `Errors` of `DynError`, i.e. an error tree whose details are type-erased.
+pub type DynErrors = ::Errors<Box<dyn AnyDebug>>;
+
+impl DynError {
+    /// Downcasts this error's details to a concrete type `U`.
+    ///
+    /// Returns `None` if there are no details, or if the details are not of type `U`.
+    pub fn details_as<U: Any>(&self) -> Option<&U> {
+        self.details().and_then(|details| AnyDebug::as_any(&**details).downcast_ref::<U>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DynError;
+    use Error;
+
+    #[derive(Debug)]
+    struct InvalidCharacters {
+        invalid_characters: Vec<char>,
+    }
+
+    #[derive(Debug)]
+    struct RangeViolation {
+        max: u32,
+    }
+
+    #[test]
+    fn details_as_downcasts_to_the_matching_type() {
+        let error: DynError = Error::with_details(
+            "has invalid characters",
+            Box::new(InvalidCharacters { invalid_characters: vec!['x'] }),
+        );
+
+        let details = error.details_as::<InvalidCharacters>().unwrap();
+
+        assert_eq!(details.invalid_characters, vec!['x']);
+    }
+
+    #[test]
+    fn details_as_returns_none_for_a_mismatched_type() {
+        let error: DynError = Error::with_details(
+            "too large",
+            Box::new(RangeViolation { max: 150 }),
+        );
+
+        assert!(error.details_as::<InvalidCharacters>().is_none());
+
+        let details = error.details_as::<RangeViolation>().unwrap();
+
+        assert_eq!(details.max, 150);
+    }
+
+    #[test]
+    fn details_as_returns_none_without_details() {
+        let error: DynError = Error::new("is invalid");
+
+        assert!(error.details_as::<InvalidCharacters>().is_none());
+    }
+}
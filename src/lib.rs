@@ -15,6 +15,38 @@
 //! to the field to validate itself and assign any resulting errors back to the parent type's
 //! errors.
 //!
+//! The `validators` module provides functions for common constraints, such as `length`, `range`,
+//! and `email`, so that a `validate` implementation doesn't have to reimplement them.
+//!
+//! `Validated<T>` (and its borrowing counterpart, `ValidatedRef<T>`) wraps a value that is known
+//! to have passed its `Validate` implementation. It can only be constructed by successfully
+//! running `validate()`, so a function signature that requires `Validated<T>` rather than `T`
+//! documents, and enforces, that its caller cannot pass in unchecked data.
+//!
+//! With the `serde` feature enabled, `Errors<T>` (and `Error<T>`) implement `Serialize`, so a
+//! failed `validate()` call can be returned directly as a structured JSON response body from a
+//! web handler.
+//!
+//! `DynError` and `DynErrors` are type-erased aliases of `Error`/`Errors` for when different
+//! fields in the same error tree need different detail types; `DynError::details_as` downcasts
+//! back to a concrete type.
+//!
+//! The `OrElse` trait adds an `or_check` combinator to `Result<(), Error<T>>` for inverting a
+//! predicate-based check; the `Validation` trait adds an `and_check` combinator for running
+//! several checks and collecting every failure into one `Errors<T>`, rather than stopping at the
+//! first. The `msg!` macro builds a replacement `Error` from a format string for use with
+//! `map_err`.
+//!
+//! `Accumulator<T>` supports the same "collect every failure" style at the level of a whole
+//! type's `validate` implementation: `push_result` merges in the errors from a sub-field's own
+//! `validate()` call, and `merge` unions two `Errors<T>` trees, so a single `finish()` call
+//! returns every problem found across all of a type's fields.
+//!
+//! For the common case of validating a struct's fields independently, the companion
+//! `validations_derive` crate provides `#[derive(Validate)]`, which generates a `Validate<()>`
+//! implementation from `#[validate(...)]` attributes on each field instead of requiring it to be
+//! written by hand.
+//!
 //! Instead of implementing `Validate`, another approach is to implement validation logic inside the
 //! constructor function of a type `T`, and return `Result<T, Errors>`, preventing an invalid value
 //! from being created in the first place. This may not always be possible, as the value may be
@@ -82,6 +114,29 @@
 #![deny(missing_docs)]
 #![deny(warnings)]
 
+/// Re-exported so `validations_derive`'s generated `#[validate(regex = "...")]` code can refer to
+/// `validations::regex` instead of requiring the consuming crate to add its own `regex`
+/// dependency.
+pub extern crate regex;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_json;
+
+pub mod validators;
+mod validated;
+mod dyn_error;
+#[macro_use]
+mod combinators;
+mod accumulator;
+#[cfg(feature = "serde")]
+mod serde_impl;
+
+pub use validated::{Validated, ValidatedRef};
+pub use dyn_error::{AnyDebug, DynError, DynErrors};
+pub use combinators::{OrElse, Validation};
+pub use accumulator::Accumulator;
+
 use std::any::Any;
 use std::collections::hash_map::{Entry, HashMap};
 use std::error::Error as StdError;
@@ -0,0 +1,101 @@
+//! `Serialize` implementations for `Error` and `Errors`, enabled by the `serde` feature.
+//!
+//! The shape mirrors the `base`/`fields` structure already used internally: an object with a
+//! `base` array of `{ message, details }` entries for errors that aren't specific to any field,
+//! and a `fields` map whose values are themselves serialized `Errors<T>`, recursing down the
+//! tree. This lets a failed `validate()` call be returned directly as a JSON response body from a
+//! web handler.
+
+use std::any::Any;
+use std::fmt::Debug;
+
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+use {Error, Errors};
+
+impl<T> Serialize for Error<T> where T: Debug + Any + Serialize {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        let mut state = serializer.serialize_struct("Error", 2)?;
+
+        state.serialize_field("message", self.message())?;
+        state.serialize_field("details", &self.details())?;
+
+        state.end()
+    }
+}
+
+impl<T> Serialize for Errors<T> where T: Debug + Any + Serialize {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        let mut state = serializer.serialize_struct("Errors", 2)?;
+
+        state.serialize_field("base", &self.base().unwrap_or(&[]))?;
+        state.serialize_field("fields", &self.fields.as_ref().map(|fields| {
+            fields.iter().map(|(field, errors)| (field.clone(), errors.as_ref())).collect::<::std::collections::HashMap<_, _>>()
+        }).unwrap_or_default())?;
+
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use {Error, Errors};
+
+    #[test]
+    fn serializes_base_only_errors() {
+        let mut errors: Errors<()> = Errors::new();
+
+        errors.add_error(Error::new("at least one phone number is required"));
+
+        let value = ::serde_json::to_value(&errors).unwrap();
+
+        assert_eq!(
+            value,
+            json!({
+                "base": [{ "message": "at least one phone number is required", "details": null }],
+                "fields": {},
+            })
+        );
+    }
+
+    #[test]
+    fn serializes_fields_only_errors() {
+        let mut errors: Errors<()> = Errors::new();
+
+        errors.add_field_error("name", Error::new("can't be blank"));
+
+        let value = ::serde_json::to_value(&errors).unwrap();
+
+        assert_eq!(
+            value,
+            json!({
+                "base": [],
+                "fields": {
+                    "name": {
+                        "base": [{ "message": "can't be blank", "details": null }],
+                        "fields": {},
+                    },
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn serializes_populated_details() {
+        let mut errors: Errors<u32> = Errors::new();
+
+        errors.add_error(Error::with_details("must be at most 150", 200));
+
+        let value = ::serde_json::to_value(&errors).unwrap();
+
+        assert_eq!(
+            value,
+            json!({
+                "base": [{ "message": "must be at most 150", "details": 200 }],
+                "fields": {},
+            })
+        );
+    }
+}
@@ -0,0 +1,147 @@
+//! A type-level guarantee that a value has passed its `Validate` implementation.
+//!
+//! `Validated<T>` can only be constructed by successfully running `T::validate`, so a function
+//! that takes a `Validated<T>` argument can trust that the value it received is valid without
+//! having to call `validate()` itself (or trust that a caller remembered to). This decouples
+//! "has been validated" from "was merely constructed" or "was deserialized from an external
+//! source."
+
+use std::any::Any;
+use std::fmt::Debug;
+use std::ops::Deref;
+
+use {Errors, Validate};
+
+/// A value of type `T` that is known to have passed validation.
+///
+/// The only way to obtain a `Validated<T>` is through `Validated::new`, which runs
+/// `T::validate` and keeps the value only if validation succeeds.
+#[derive(Debug)]
+pub struct Validated<T> {
+    value: T,
+}
+
+impl<T> Validated<T> {
+    /// Validates `value` and, if valid, wraps it in a `Validated<T>`.
+    ///
+    /// Returns the `Errors` produced by validation if `value` is invalid.
+    pub fn new<E>(value: T) -> Result<Validated<T>, Errors<E>>
+        where T: Validate<E>, E: Debug + Any
+    {
+        match value.validate() {
+            Ok(()) => Ok(Validated { value }),
+            Err(errors) => Err(errors),
+        }
+    }
+
+    /// Unwraps the validated value, discarding the proof that it was validated.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Deref for Validated<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+/// A reference to a value of type `T` that is known to have passed validation.
+///
+/// This is the borrowing counterpart to `Validated<T>`, useful when the caller doesn't want to
+/// take ownership of the value just to prove it's valid.
+#[derive(Debug)]
+pub struct ValidatedRef<'a, T> where T: 'a {
+    value: &'a T,
+}
+
+impl<'a, T> ValidatedRef<'a, T> where T: 'a {
+    /// Validates `value` and, if valid, wraps the reference in a `ValidatedRef<T>`.
+    ///
+    /// Returns the `Errors` produced by validation if `value` is invalid.
+    pub fn new<E>(value: &'a T) -> Result<ValidatedRef<'a, T>, Errors<E>>
+        where T: Validate<E>, E: Debug + Any
+    {
+        match value.validate() {
+            Ok(()) => Ok(ValidatedRef { value }),
+            Err(errors) => Err(errors),
+        }
+    }
+
+    /// Unwraps the validated reference, discarding the proof that it was validated.
+    pub fn into_inner(self) -> &'a T {
+        self.value
+    }
+}
+
+impl<'a, T> Deref for ValidatedRef<'a, T> where T: 'a {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Validated, ValidatedRef};
+    use {Error, Errors, Validate};
+
+    #[derive(Debug)]
+    struct Name(&'static str);
+
+    impl Validate<()> for Name {
+        fn validate(&self) -> Result<(), Errors<()>> {
+            if self.0.is_empty() {
+                let mut errors = Errors::new();
+
+                errors.add_error(Error::new("can't be blank"));
+
+                Err(errors)
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    #[test]
+    fn new_succeeds_for_valid_value() {
+        let validated = Validated::new(Name("Rust Cohle"));
+
+        assert!(validated.is_ok());
+        assert_eq!(validated.unwrap().0, "Rust Cohle");
+    }
+
+    #[test]
+    fn new_fails_for_invalid_value() {
+        let validated = Validated::new(Name(""));
+
+        assert!(validated.is_err());
+    }
+
+    #[test]
+    fn into_inner_returns_the_value() {
+        let validated = Validated::new(Name("Rust Cohle")).unwrap();
+
+        assert_eq!(validated.into_inner().0, "Rust Cohle");
+    }
+
+    #[test]
+    fn validated_ref_succeeds_for_valid_value() {
+        let name = Name("Rust Cohle");
+        let validated = ValidatedRef::new(&name);
+
+        assert!(validated.is_ok());
+        assert_eq!(validated.unwrap().0, "Rust Cohle");
+    }
+
+    #[test]
+    fn validated_ref_fails_for_invalid_value() {
+        let name = Name("");
+        let validated = ValidatedRef::new(&name);
+
+        assert!(validated.is_err());
+    }
+}
@@ -0,0 +1,389 @@
+//! Reusable validator functions for common constraints.
+//!
+//! Each check function here checks a single constraint against a value and returns `Result<(), Error<T>>`,
+//! so it can be called directly from inside a hand-written `Validate::validate` implementation (or
+//! from code generated by `#[derive(Validate)]`) without reimplementing things like Luhn checksums
+//! or email parsing for every type that needs them.
+//!
+//! Every check function accepts an optional `message` to override its default, human-readable message.
+//! Passing `None` falls back to a message that describes the failed constraint on its own.
+
+use std::any::Any;
+use std::fmt::{Debug, Display};
+
+use {Error, Errors};
+
+/// Types that have a length that can be checked by `length`.
+///
+/// This is implemented for the standard string and collection types; it exists so `length` isn't
+/// restricted to a single concrete type.
+pub trait HasLength {
+    /// The number of elements (or characters, for strings) in the value.
+    fn length(&self) -> usize;
+}
+
+impl HasLength for str {
+    fn length(&self) -> usize {
+        self.chars().count()
+    }
+}
+
+impl HasLength for String {
+    fn length(&self) -> usize {
+        self.as_str().length()
+    }
+}
+
+impl<T> HasLength for [T] {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> HasLength for Vec<T> {
+    fn length(&self) -> usize {
+        self.as_slice().length()
+    }
+}
+
+/// Checks that `value`'s length falls within `min` and `max` (inclusive), or equals `equal` when
+/// given. At least one of `min`, `max`, or `equal` should be provided.
+pub fn length<T, V>(
+    value: &V,
+    min: Option<usize>,
+    max: Option<usize>,
+    equal: Option<usize>,
+    message: Option<&str>,
+) -> Result<(), Error<T>>
+    where T: Debug + Any, V: HasLength + ?Sized
+{
+    let length = value.length();
+
+    if let Some(equal) = equal {
+        if length != equal {
+            return Err(Error::new(
+                message.map(String::from).unwrap_or_else(|| format!("must be exactly {} characters long", equal))
+            ));
+        }
+    }
+
+    if let Some(min) = min {
+        if length < min {
+            return Err(Error::new(
+                message.map(String::from).unwrap_or_else(|| format!("must be at least {} characters long", min))
+            ));
+        }
+    }
+
+    if let Some(max) = max {
+        if length > max {
+            return Err(Error::new(
+                message.map(String::from).unwrap_or_else(|| format!("must be at most {} characters long", max))
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that `value` falls within `min` and `max` (inclusive), or equals `equal` when given. At
+/// least one of `min`, `max`, or `equal` should be provided.
+pub fn range<T, N>(
+    value: N,
+    min: Option<N>,
+    max: Option<N>,
+    equal: Option<N>,
+    message: Option<&str>,
+) -> Result<(), Error<T>>
+    where T: Debug + Any, N: Copy + Display + PartialOrd
+{
+    if let Some(equal) = equal {
+        if value != equal {
+            return Err(Error::new(
+                message.map(String::from).unwrap_or_else(|| format!("must be exactly {}", equal))
+            ));
+        }
+    }
+
+    if let Some(min) = min {
+        if value < min {
+            return Err(Error::new(
+                message.map(String::from).unwrap_or_else(|| format!("must be no less than {}", min))
+            ));
+        }
+    }
+
+    if let Some(max) = max {
+        if value > max {
+            return Err(Error::new(
+                message.map(String::from).unwrap_or_else(|| format!("must be no greater than {}", max))
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that `value` looks like an email address.
+///
+/// This performs a practical, not exhaustive, check: a single `@` separating a non-empty local
+/// part from a domain part that itself contains a `.`.
+pub fn email<T>(value: &str, message: Option<&str>) -> Result<(), Error<T>> where T: Debug + Any {
+    let valid = match value.find('@') {
+        Some(at) => {
+            let (local, domain) = (&value[..at], &value[at + 1..]);
+
+            !local.is_empty() && !domain.contains('@') && domain.contains('.') && !domain.starts_with('.') && !domain.ends_with('.')
+        }
+        None => false,
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::new(message.unwrap_or("is not a valid email address")))
+    }
+}
+
+/// Checks that `value` looks like a URL, i.e. a scheme made of letters followed by `://` and a
+/// non-empty remainder.
+pub fn url<T>(value: &str, message: Option<&str>) -> Result<(), Error<T>> where T: Debug + Any {
+    let valid = match value.find("://") {
+        Some(scheme_end) => {
+            let scheme = &value[..scheme_end];
+            let rest = &value[scheme_end + 3..];
+
+            !scheme.is_empty() && scheme.chars().all(|c| c.is_alphabetic()) && !rest.is_empty()
+        }
+        None => false,
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::new(message.unwrap_or("is not a valid URL")))
+    }
+}
+
+/// Checks that `value` is a valid IPv4 or IPv6 address.
+pub fn ip<T>(value: &str, message: Option<&str>) -> Result<(), Error<T>> where T: Debug + Any {
+    use std::net::IpAddr;
+
+    if value.parse::<IpAddr>().is_ok() {
+        Ok(())
+    } else {
+        Err(Error::new(message.unwrap_or("is not a valid IP address")))
+    }
+}
+
+/// Checks that `value` is a valid credit card number, via the Luhn algorithm.
+pub fn credit_card<T>(value: &str, message: Option<&str>) -> Result<(), Error<T>> where T: Debug + Any {
+    let digits: Vec<u32> = value.chars().filter(|c| !c.is_whitespace() && *c != '-').map(|c| c.to_digit(10)).collect::<Option<Vec<u32>>>().unwrap_or_default();
+
+    let valid = digits.len() >= 2 && luhn_checksum(&digits) % 10 == 0;
+
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::new(message.unwrap_or("is not a valid credit card number")))
+    }
+}
+
+fn luhn_checksum(digits: &[u32]) -> u32 {
+    digits.iter().rev().enumerate().map(|(i, &digit)| {
+        if i % 2 == 1 {
+            let doubled = digit * 2;
+
+            if doubled > 9 { doubled - 9 } else { doubled }
+        } else {
+            digit
+        }
+    }).sum()
+}
+
+/// Checks that `value` contains `needle`.
+pub fn contains<T>(value: &str, needle: &str, message: Option<&str>) -> Result<(), Error<T>> where T: Debug + Any {
+    if value.contains(needle) {
+        Ok(())
+    } else {
+        Err(Error::new(
+            message.map(String::from).unwrap_or_else(|| format!("must contain {}", needle))
+        ))
+    }
+}
+
+/// Checks that `value` does not contain `needle`.
+pub fn does_not_contain<T>(value: &str, needle: &str, message: Option<&str>) -> Result<(), Error<T>> where T: Debug + Any {
+    if value.contains(needle) {
+        Err(Error::new(
+            message.map(String::from).unwrap_or_else(|| format!("must not contain {}", needle))
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that `a` and `b` are equal, for fields like password confirmations that must match
+/// another field.
+pub fn must_match<T>(a: &str, b: &str, message: Option<&str>) -> Result<(), Error<T>> where T: Debug + Any {
+    if a == b {
+        Ok(())
+    } else {
+        Err(Error::new(message.unwrap_or("does not match")))
+    }
+}
+
+/// Checks that `value` contains no control characters.
+pub fn non_control_character<T>(value: &str, message: Option<&str>) -> Result<(), Error<T>> where T: Debug + Any {
+    if value.chars().any(|c| c.is_control()) {
+        Err(Error::new(message.unwrap_or("must not contain control characters")))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that `value` matches the given regular expression.
+///
+/// This compiles `pattern` on every call; callers that already have a compiled `Regex` (such as
+/// `#[derive(Validate)]`, which can cache one per field) should call `matches_regex` directly
+/// instead.
+pub fn regex<T>(value: &str, pattern: &str, message: Option<&str>) -> Result<(), Error<T>> where T: Debug + Any {
+    let regex = ::regex::Regex::new(pattern).expect("invalid regex passed to validators::regex");
+
+    matches_regex(value, &regex, message)
+}
+
+/// Checks that `value` matches an already-compiled `regex`.
+pub fn matches_regex<T>(value: &str, regex: &::regex::Regex, message: Option<&str>) -> Result<(), Error<T>> where T: Debug + Any {
+    if regex.is_match(value) {
+        Ok(())
+    } else {
+        Err(Error::new(message.unwrap_or("is not in the correct format")))
+    }
+}
+
+/// Runs `result` and, if it failed, adds the error to `errors` under `field`.
+///
+/// This is a convenience for the common pattern of calling one of the functions above and
+/// forwarding any failure into the `Errors` value being built up by a `validate` implementation.
+pub fn push_field_error<T, S>(errors: &mut Errors<T>, field: S, result: Result<(), Error<T>>)
+    where T: Debug + Any, S: Into<String>
+{
+    if let Err(error) = result {
+        errors.add_field_error(field, error);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn length_within_bounds() {
+        let result: Result<(), Error<()>> = length("hello", Some(1), Some(10), None, None);
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn length_too_short() {
+        let result: Result<(), Error<()>> = length("hi", Some(3), None, None, None);
+
+        assert_eq!(result.err().unwrap().message(), "must be at least 3 characters long");
+    }
+
+    #[test]
+    fn length_custom_message() {
+        let result: Result<(), Error<()>> = length("hi", Some(3), None, None, Some("too short"));
+
+        assert_eq!(result.err().unwrap().message(), "too short");
+    }
+
+    #[test]
+    fn range_within_bounds() {
+        let result: Result<(), Error<()>> = range(50, Some(0), Some(100), None, None);
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn range_too_large() {
+        let result: Result<(), Error<()>> = range(200, Some(0), Some(100), None, None);
+
+        assert_eq!(result.err().unwrap().message(), "must be no greater than 100");
+    }
+
+    #[test]
+    fn email_valid() {
+        let result: Result<(), Error<()>> = email("rcohle@dps.la.gov", None);
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn email_invalid() {
+        let result: Result<(), Error<()>> = email("rcohle", None);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn url_valid() {
+        let result: Result<(), Error<()>> = url("https://example.com", None);
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn ip_valid_v4_and_v6() {
+        let v4: Result<(), Error<()>> = ip("127.0.0.1", None);
+        let v6: Result<(), Error<()>> = ip("::1", None);
+
+        assert!(v4.is_ok());
+        assert!(v6.is_ok());
+    }
+
+    #[test]
+    fn credit_card_valid() {
+        let result: Result<(), Error<()>> = credit_card("4111111111111111", None);
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn credit_card_invalid() {
+        let result: Result<(), Error<()>> = credit_card("4111111111111112", None);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn contains_and_does_not_contain() {
+        let found: Result<(), Error<()>> = contains("hello world", "world", None);
+        let missing: Result<(), Error<()>> = does_not_contain("hello world", "xyz", None);
+
+        assert!(found.is_ok());
+        assert!(missing.is_ok());
+    }
+
+    #[test]
+    fn must_match_mismatch() {
+        let result: Result<(), Error<()>> = must_match("abc", "abd", None);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn non_control_character_rejects_control_characters() {
+        let result: Result<(), Error<()>> = non_control_character("hello\u{0007}", None);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn regex_matches_pattern() {
+        let result: Result<(), Error<()>> = regex("abc123", r"^[a-z]+\d+$", None);
+
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,421 @@
+//! Procedural macro companion to the `validations` crate.
+//!
+//! This crate provides `#[derive(Validate)]`, which generates a `Validate` implementation from
+//! declarative `#[validate(...)]` attributes on a struct's fields, so the boilerplate of building
+//! up an `Errors` value by hand (as shown in `validations`'s own tests) doesn't have to be
+//! repeated for every type.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! #[derive(Validate)]
+//! struct SignupForm {
+//!     #[validate(length(min = 1, message = "can't be blank"))]
+//!     name: String,
+//!
+//!     #[validate(email)]
+//!     email: String,
+//!
+//!     #[validate(range(min = 0, max = 150))]
+//!     age: u32,
+//!
+//!     #[validate]
+//!     address: Address,
+//! }
+//! ```
+//!
+//! Each attribute corresponds to a check performed on the field's value. A field may carry more
+//! than one `#[validate(...)]` attribute, and all of its checks are run before moving on to the
+//! next field, so a single call to `validate()` reports every violation at once rather than only
+//! the first one encountered.
+//!
+//! `email`, `regex`, and `custom` also accept a list form that overrides the default message, the
+//! same way `length`/`range` already do: `#[validate(email(message = "..."))]`,
+//! `#[validate(regex(pattern = "...", message = "..."))]`, and
+//! `#[validate(custom(path = "my_fn", message = "..."))]`.
+
+extern crate proc_macro;
+extern crate proc_macro2;
+extern crate quote;
+extern crate regex;
+extern crate syn;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input, Data, DeriveInput, Fields, Lit, Meta, MetaNameValue, NestedMeta, Path,
+};
+
+/// Derives a `Validate<()>` implementation from `#[validate(...)]` field attributes.
+///
+/// See the crate-level documentation for the supported attribute forms.
+#[proc_macro_derive(Validate, attributes(validate))]
+pub fn derive_validate(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let fields = match input.data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) => &fields.named,
+            _ => panic!("#[derive(Validate)] only supports structs with named fields"),
+        },
+        _ => panic!("#[derive(Validate)] only supports structs"),
+    };
+
+    let field_checks = fields.iter().map(|field| {
+        let field_ident = field.ident.as_ref().expect("named field");
+        let field_name = field_ident.to_string();
+
+        let mut skip_if = None;
+        let mut checks = Vec::new();
+
+        for attr in &field.attrs {
+            if !attr.path.is_ident("validate") {
+                continue;
+            }
+
+            // A bare `#[validate]` delegates to the field's own `Validate` implementation.
+            if attr.tokens.is_empty() {
+                checks.push(nested_validate_check(field_ident, &field_name));
+                continue;
+            }
+
+            let meta = attr
+                .parse_meta()
+                .unwrap_or_else(|err| panic!("invalid #[validate(...)] attribute: {}", err));
+
+            let list = match meta {
+                Meta::List(list) => list,
+                _ => panic!("expected #[validate(...)]"),
+            };
+
+            for nested in list.nested {
+                match nested {
+                    NestedMeta::Meta(Meta::Path(ref path)) if path.is_ident("email") => {
+                        checks.push(email_check(field_ident, &field_name, None));
+                    }
+                    NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                        ref path, ref lit, ..
+                    })) if path.is_ident("skip_if") => {
+                        skip_if = Some(custom_fn_path(lit));
+                    }
+                    NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                        ref path, ref lit, ..
+                    })) if path.is_ident("regex") => {
+                        checks.push(regex_check(field_ident, &field_name, lit, None));
+                    }
+                    NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                        ref path, ref lit, ..
+                    })) if path.is_ident("custom") => {
+                        checks.push(custom_check(field_ident, &field_name, lit, None));
+                    }
+                    NestedMeta::Meta(Meta::List(ref list)) if list.path.is_ident("length") => {
+                        checks.push(length_or_range_check(
+                            "length",
+                            field_ident,
+                            &field_name,
+                            list,
+                        ));
+                    }
+                    NestedMeta::Meta(Meta::List(ref list)) if list.path.is_ident("range") => {
+                        checks.push(length_or_range_check(
+                            "range",
+                            field_ident,
+                            &field_name,
+                            list,
+                        ));
+                    }
+                    // List form, e.g. `#[validate(email(message = "..."))]`, so a per-field
+                    // message override can reach `email_check`.
+                    NestedMeta::Meta(Meta::List(ref list)) if list.path.is_ident("email") => {
+                        let message = list_lit(list, "message");
+
+                        checks.push(email_check(field_ident, &field_name, message.as_ref()));
+                    }
+                    // List form, e.g. `#[validate(regex(pattern = "...", message = "..."))]`, so
+                    // a per-field message override can reach `regex_check`.
+                    NestedMeta::Meta(Meta::List(ref list)) if list.path.is_ident("regex") => {
+                        let pattern = list_lit(list, "pattern").unwrap_or_else(|| {
+                            panic!("#[validate(regex(...))] requires a `pattern`")
+                        });
+                        let message = list_lit(list, "message");
+
+                        checks.push(regex_check(field_ident, &field_name, &pattern, message.as_ref()));
+                    }
+                    // List form, e.g. `#[validate(custom(path = "my_fn", message = "..."))]`, so
+                    // a per-field message override can reach `custom_check`.
+                    NestedMeta::Meta(Meta::List(ref list)) if list.path.is_ident("custom") => {
+                        let path = list_lit(list, "path").unwrap_or_else(|| {
+                            panic!("#[validate(custom(...))] requires a `path`")
+                        });
+                        let message = list_lit(list, "message");
+
+                        checks.push(custom_check(field_ident, &field_name, &path, message.as_ref()));
+                    }
+                    // `{:?}` on a `syn::NestedMeta` would require syn's non-default
+                    // `extra-traits` feature; `quote!` (via `ToTokens`) renders the offending
+                    // attribute without it.
+                    other => panic!(
+                        "unsupported #[validate(...)] attribute: {}",
+                        quote! { #other }
+                    ),
+                }
+            }
+        }
+
+        let body: TokenStream2 = checks.into_iter().collect();
+
+        match skip_if {
+            Some(skip_fn) => quote! {
+                if !#skip_fn(self) {
+                    #body
+                }
+            },
+            None => body,
+        }
+    });
+
+    let expanded = quote! {
+        impl validations::Validate<()> for #name {
+            fn validate(&self) -> Result<(), validations::Errors<()>> {
+                let mut errors = validations::Errors::new();
+
+                #(#field_checks)*
+
+                if errors.is_empty() {
+                    Ok(())
+                } else {
+                    Err(errors)
+                }
+            }
+        }
+    };
+
+    expanded.into()
+}
+
+/// Extracts `min`/`max`/`equal` name-value pairs out of a `length(...)` or `range(...)` list and
+/// builds a call into `validations::validators::length`/`validations::validators::range` for
+/// each bound present.
+fn length_or_range_check(
+    kind: &str,
+    field_ident: &syn::Ident,
+    field_name: &str,
+    list: &syn::MetaList,
+) -> TokenStream2 {
+    let mut min = None;
+    let mut max = None;
+    let mut equal = None;
+    let mut message = None;
+
+    for nested in &list.nested {
+        if let NestedMeta::Meta(Meta::NameValue(MetaNameValue { path, lit, .. })) = nested {
+            if path.is_ident("min") {
+                min = Some(lit.clone());
+            } else if path.is_ident("max") {
+                max = Some(lit.clone());
+            } else if path.is_ident("equal") {
+                equal = Some(lit.clone());
+            } else if path.is_ident("message") {
+                message = Some(lit.clone());
+            }
+        }
+    }
+
+    let validator = if kind == "length" {
+        quote! { validations::validators::length }
+    } else {
+        quote! { validations::validators::range }
+    };
+
+    let value = if kind == "length" {
+        quote! { &self.#field_ident }
+    } else {
+        quote! { self.#field_ident }
+    };
+
+    // Each bound gets its own call, with the other two left unset, and its own default message,
+    // rather than being OR'd together behind one message, so a min violation isn't reported with
+    // the max bound's message (or vice versa) when a field carries more than one bound.
+    let mut bound_checks = Vec::new();
+
+    if let Some(ref min) = min {
+        let default_message = format!("must be at least {}", lit_value(min));
+        let resolved_message = message
+            .clone()
+            .map(|lit| quote! { #lit })
+            .unwrap_or_else(|| quote! { #default_message });
+
+        bound_checks.push(quote! {
+            validations::validators::push_field_error(
+                &mut errors,
+                #field_name,
+                #validator(#value, Some(#min), None, None, Some(#resolved_message)),
+            );
+        });
+    }
+
+    if let Some(ref max) = max {
+        let default_message = format!("must be at most {}", lit_value(max));
+        let resolved_message = message
+            .clone()
+            .map(|lit| quote! { #lit })
+            .unwrap_or_else(|| quote! { #default_message });
+
+        bound_checks.push(quote! {
+            validations::validators::push_field_error(
+                &mut errors,
+                #field_name,
+                #validator(#value, None, Some(#max), None, Some(#resolved_message)),
+            );
+        });
+    }
+
+    if let Some(ref equal) = equal {
+        let default_message = format!("must be exactly {}", lit_value(equal));
+        let resolved_message = message
+            .clone()
+            .map(|lit| quote! { #lit })
+            .unwrap_or_else(|| quote! { #default_message });
+
+        bound_checks.push(quote! {
+            validations::validators::push_field_error(
+                &mut errors,
+                #field_name,
+                #validator(#value, None, None, Some(#equal), Some(#resolved_message)),
+            );
+        });
+    }
+
+    if bound_checks.is_empty() {
+        panic!("length/range requires at least one of min, max, or equal");
+    }
+
+    quote! { #(#bound_checks)* }
+}
+
+/// Builds the check for `#[validate(email)]`.
+fn email_check(
+    field_ident: &syn::Ident,
+    field_name: &str,
+    message: Option<&Lit>,
+) -> TokenStream2 {
+    let message = message
+        .map(|lit| quote! { #lit })
+        .unwrap_or_else(|| quote! { "is not a valid email address" });
+
+    quote! {
+        validations::validators::push_field_error(
+            &mut errors,
+            #field_name,
+            validations::validators::email(self.#field_ident.as_ref(), Some(#message)),
+        );
+    }
+}
+
+/// Builds the check for `#[validate(regex = "...")]`.
+///
+/// The pattern is compiled here, at macro-expansion time, so an invalid pattern is a compile
+/// error in the consuming crate instead of a panic the first time `validate()` runs. The
+/// generated code then caches the compiled `Regex` in a `static`, so `validate()` doesn't pay to
+/// recompile the same pattern on every call.
+fn regex_check(
+    field_ident: &syn::Ident,
+    field_name: &str,
+    pattern: &Lit,
+    message: Option<&Lit>,
+) -> TokenStream2 {
+    let pattern_str = match pattern {
+        Lit::Str(s) => s.value(),
+        _ => panic!("expected a string literal for #[validate(regex = ...)]"),
+    };
+
+    regex::Regex::new(&pattern_str)
+        .unwrap_or_else(|err| panic!("invalid regex in #[validate(regex = ...)]: {}", err));
+
+    let message = message
+        .map(|lit| quote! { #lit })
+        .unwrap_or_else(|| quote! { "is not in the correct format" });
+
+    quote! {
+        {
+            static REGEX: ::std::sync::OnceLock<validations::regex::Regex> = ::std::sync::OnceLock::new();
+            let regex = REGEX.get_or_init(|| {
+                validations::regex::Regex::new(#pattern).expect("invalid regex in #[validate(regex = ...)]")
+            });
+
+            validations::validators::push_field_error(
+                &mut errors,
+                #field_name,
+                validations::validators::matches_regex(self.#field_ident.as_ref(), regex, Some(#message)),
+            );
+        }
+    }
+}
+
+/// Builds the check for `#[validate(custom = "my_fn")]`, where `my_fn` is a function of
+/// signature `fn(&FieldType) -> Result<(), String>`.
+fn custom_check(
+    field_ident: &syn::Ident,
+    field_name: &str,
+    path_lit: &Lit,
+    message: Option<&Lit>,
+) -> TokenStream2 {
+    let path = custom_fn_path(path_lit);
+
+    let error = match message {
+        Some(lit) => quote! { validations::Error::new(#lit) },
+        None => quote! { validations::Error::new(message) },
+    };
+
+    quote! {
+        if let Err(message) = #path(&self.#field_ident) {
+            errors.add_field_error(#field_name, #error);
+        }
+    }
+}
+
+/// Builds the check for a bare `#[validate]`, which delegates to the field's own
+/// `Validate<()>` implementation via `set_field_errors`.
+fn nested_validate_check(field_ident: &syn::Ident, field_name: &str) -> TokenStream2 {
+    quote! {
+        if let Err(field_errors) = self.#field_ident.validate() {
+            errors.set_field_errors(#field_name, field_errors);
+        }
+    }
+}
+
+/// Parses a string literal naming a function into a callable path.
+fn custom_fn_path(lit: &Lit) -> Path {
+    match lit {
+        Lit::Str(s) => s
+            .parse()
+            .unwrap_or_else(|err| panic!("invalid function path {:?}: {}", s.value(), err)),
+        _ => panic!("expected a string literal naming a function"),
+    }
+}
+
+/// Finds a name-value pair called `name` inside a `#[validate(...)]` list, e.g. `message` in
+/// `#[validate(email(message = "..."))]`.
+fn list_lit(list: &syn::MetaList, name: &str) -> Option<Lit> {
+    list.nested.iter().find_map(|nested| {
+        if let NestedMeta::Meta(Meta::NameValue(MetaNameValue { path, lit, .. })) = nested {
+            if path.is_ident(name) {
+                return Some(lit.clone());
+            }
+        }
+
+        None
+    })
+}
+
+/// Renders a literal's value for inclusion in a generated default error message.
+fn lit_value(lit: &Lit) -> String {
+    match lit {
+        Lit::Int(i) => i.base10_digits().to_string(),
+        Lit::Float(f) => f.base10_digits().to_string(),
+        Lit::Str(s) => s.value(),
+        _ => quote!(#lit).to_string(),
+    }
+}
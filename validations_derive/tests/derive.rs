@@ -0,0 +1,199 @@
+//! Smoke tests proving `#[derive(Validate)]` expands into a working `Validate<()>`
+//! implementation with the expected field errors.
+
+extern crate validations;
+extern crate validations_derive;
+
+use validations::Validate;
+use validations_derive::Validate;
+
+#[derive(Validate)]
+struct SignupForm {
+    #[validate(length(min = 1, message = "can't be blank"))]
+    name: String,
+
+    #[validate(email)]
+    email: String,
+
+    #[validate(range(min = 0, max = 150))]
+    age: u32,
+}
+
+#[test]
+fn validate_passes_when_every_field_is_valid() {
+    let form = SignupForm {
+        name: "Ferris".to_string(),
+        email: "ferris@example.com".to_string(),
+        age: 12,
+    };
+
+    assert!(form.validate().is_ok());
+}
+
+#[test]
+fn validate_reports_every_invalid_field() {
+    let form = SignupForm {
+        name: "".to_string(),
+        email: "not-an-email".to_string(),
+        age: 200,
+    };
+
+    let errors = form.validate().err().unwrap();
+
+    assert_eq!(errors.field("name").unwrap().base().unwrap()[0].message(), "can't be blank");
+    assert_eq!(
+        errors.field("email").unwrap().base().unwrap()[0].message(),
+        "is not a valid email address"
+    );
+    assert_eq!(
+        errors.field("age").unwrap().base().unwrap()[0].message(),
+        "must be at most 150"
+    );
+}
+
+#[test]
+fn email_rejects_an_address_with_no_domain() {
+    let form = SignupForm {
+        name: "Ferris".to_string(),
+        email: "test@".to_string(),
+        age: 12,
+    };
+
+    let errors = form.validate().err().unwrap();
+
+    assert_eq!(
+        errors.field("email").unwrap().base().unwrap()[0].message(),
+        "is not a valid email address"
+    );
+}
+
+#[derive(Validate)]
+struct Address {
+    #[validate(length(min = 1, message = "can't be blank"))]
+    street: String,
+}
+
+#[derive(Validate)]
+struct AddressBookEntry {
+    #[validate(length(min = 1, message = "can't be blank"))]
+    name: String,
+
+    #[validate]
+    address: Address,
+}
+
+#[test]
+fn validate_delegates_to_a_nested_fields_own_validate() {
+    let entry = AddressBookEntry {
+        name: "Ferris".to_string(),
+        address: Address { street: "".to_string() },
+    };
+
+    let errors = entry.validate().err().unwrap();
+
+    assert_eq!(
+        errors.field("address").unwrap().field("street").unwrap().base().unwrap()[0].message(),
+        "can't be blank"
+    );
+}
+
+#[derive(Validate)]
+struct BoundedName {
+    #[validate(length(min = 3, max = 10))]
+    name: String,
+}
+
+#[test]
+fn length_reports_the_min_messsage_when_the_min_bound_is_the_one_that_fails() {
+    let bounded = BoundedName { name: "a".to_string() };
+
+    let errors = bounded.validate().err().unwrap();
+
+    assert_eq!(
+        errors.field("name").unwrap().base().unwrap()[0].message(),
+        "must be at least 3"
+    );
+}
+
+#[test]
+fn length_reports_the_max_message_when_the_max_bound_is_the_one_that_fails() {
+    let bounded = BoundedName { name: "way too long a name".to_string() };
+
+    let errors = bounded.validate().err().unwrap();
+
+    assert_eq!(
+        errors.field("name").unwrap().base().unwrap()[0].message(),
+        "must be at most 10"
+    );
+}
+
+fn is_lowercase(username: &str) -> Result<(), String> {
+    if username.chars().all(|c| c.is_lowercase() || !c.is_alphabetic()) {
+        Ok(())
+    } else {
+        Err("must be all lowercase".to_string())
+    }
+}
+
+fn is_blank(name: &str) -> bool {
+    name.is_empty()
+}
+
+#[derive(Validate)]
+struct Account {
+    #[validate(regex(pattern = "^[a-z0-9_]+$", message = "is not a valid handle"))]
+    handle: String,
+
+    #[validate(custom = "is_lowercase")]
+    username: String,
+
+    #[validate(skip_if = "nickname_is_blank")]
+    #[validate(length(min = 2, message = "must be at least 2 characters"))]
+    nickname: String,
+}
+
+fn nickname_is_blank(account: &Account) -> bool {
+    is_blank(&account.nickname)
+}
+
+#[test]
+fn validate_passes_for_a_valid_account() {
+    let account = Account {
+        handle: "ferris_the_crab".to_string(),
+        username: "ferris".to_string(),
+        nickname: "Ferris".to_string(),
+    };
+
+    assert!(account.validate().is_ok());
+}
+
+#[test]
+fn regex_and_custom_checks_report_their_messages() {
+    let account = Account {
+        handle: "Not A Handle!".to_string(),
+        username: "Ferris".to_string(),
+        nickname: "Ferris".to_string(),
+    };
+
+    let errors = account.validate().err().unwrap();
+
+    assert_eq!(
+        errors.field("handle").unwrap().base().unwrap()[0].message(),
+        "is not a valid handle"
+    );
+    assert_eq!(
+        errors.field("username").unwrap().base().unwrap()[0].message(),
+        "must be all lowercase"
+    );
+}
+
+#[test]
+fn skip_if_skips_the_guarded_checks_when_the_predicate_is_true() {
+    let account = Account {
+        handle: "ferris_the_crab".to_string(),
+        username: "ferris".to_string(),
+        nickname: "".to_string(),
+    };
+
+    assert!(account.validate().is_ok());
+}